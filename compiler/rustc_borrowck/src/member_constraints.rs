@@ -2,7 +2,7 @@ use std::hash::Hash;
 use std::ops::Index;
 
 use rustc_data_structures::fx::FxIndexMap;
-use rustc_index::{IndexSlice, IndexVec};
+use rustc_index::{Idx, IndexSlice, IndexVec};
 use rustc_middle::ty::{self, Ty};
 use rustc_span::Span;
 use tracing::instrument;
@@ -146,6 +146,46 @@ impl<'tcx, R> MemberConstraintSet<'tcx, R>
 where
     R: Copy + Hash + Eq,
 {
+    /// Appends all of `other`'s member constraints onto `self`, offsetting
+    /// `other`'s `choice_regions` indices and renumbering its constraint
+    /// indices so that they land after `self`'s own. Where both sets already
+    /// have constraints for the same `R0`, the two linked lists are spliced
+    /// together (via `append_list`) rather than one overwriting the other.
+    ///
+    /// This lets independently built constraint sets -- e.g. from
+    /// sub-regions solved in parallel -- be combined into one.
+    pub(crate) fn extend(&mut self, other: MemberConstraintSet<'tcx, R>) {
+        let MemberConstraintSet {
+            first_constraints: other_first_constraints,
+            constraints: other_constraints,
+            choice_regions: other_choice_regions,
+        } = other;
+
+        let choice_regions_offset = self.choice_regions.len();
+        self.choice_regions.extend(other_choice_regions);
+
+        let constraints_offset = self.constraints.len();
+        self.constraints.extend(other_constraints.into_iter().map(|c| MemberConstraint {
+            next_constraint: c
+                .next_constraint
+                .map(|next| NllMemberConstraintIndex::new(next.index() + constraints_offset)),
+            start_index: c.start_index + choice_regions_offset,
+            end_index: c.end_index + choice_regions_offset,
+            ..c
+        }));
+
+        for (r0, other_start) in other_first_constraints {
+            let other_start =
+                NllMemberConstraintIndex::new(other_start.index() + constraints_offset);
+            match self.first_constraints.get(&r0) {
+                Some(&self_start) => append_list(&mut self.constraints, self_start, other_start),
+                None => {
+                    self.first_constraints.insert(r0, other_start);
+                }
+            }
+        }
+    }
+
     pub(crate) fn all_indices(&self) -> impl Iterator<Item = NllMemberConstraintIndex> {
         self.constraints.indices()
     }
@@ -178,6 +218,82 @@ where
         let MemberConstraint { start_index, end_index, .. } = &self.constraints[pci];
         &self.choice_regions[*start_index..*end_index]
     }
+
+    /// Removes all member constraints whose `R0` is `member_region_vid`.
+    ///
+    /// This unlinks the entries from `first_constraints`, but leaves the
+    /// dropped entries (and their `choice_regions` ranges) in place as
+    /// tombstones, since `choice_regions` is a flat vector shared by every
+    /// constraint; call [`Self::compact`] to reclaim that space.
+    pub(crate) fn remove_constraints(&mut self, member_region_vid: R) {
+        self.first_constraints.shift_remove(&member_region_vid);
+    }
+
+    /// Like [`Self::remove_constraints`], but keeps only the constraints for
+    /// `member_region_vid` for which `keep` returns `true`, splicing the
+    /// linked list around the dropped entries.
+    pub(crate) fn retain_constraints(
+        &mut self,
+        member_region_vid: R,
+        mut keep: impl FnMut(NllMemberConstraintIndex) -> bool,
+    ) {
+        let mut current = self.first_constraints.get(&member_region_vid).copied();
+        let mut first_kept = None;
+        let mut prev_kept: Option<NllMemberConstraintIndex> = None;
+        while let Some(index) = current {
+            current = self.constraints[index].next_constraint;
+            if !keep(index) {
+                continue;
+            }
+            match prev_kept {
+                Some(prev) => self.constraints[prev].next_constraint = Some(index),
+                None => first_kept = Some(index),
+            }
+            self.constraints[index].next_constraint = None;
+            prev_kept = Some(index);
+        }
+
+        match first_kept {
+            Some(first) => {
+                self.first_constraints.insert(member_region_vid, first);
+            }
+            None => {
+                self.first_constraints.shift_remove(&member_region_vid);
+            }
+        }
+    }
+
+    /// Rebuilds `choice_regions`, dropping the ranges of any constraint no
+    /// longer reachable from `first_constraints` (e.g. left behind by
+    /// [`Self::remove_constraints`] or [`Self::retain_constraints`]), and
+    /// rewrites each remaining constraint's `start_index`/`end_index` to
+    /// match. Unreachable constraints are left in `constraints` -- their
+    /// indices may still be referenced elsewhere -- but their range is
+    /// collapsed to empty.
+    pub(crate) fn compact(&mut self) {
+        let mut reachable = IndexVec::from_elem(false, &self.constraints);
+        for &start in self.first_constraints.values() {
+            let mut current = Some(start);
+            while let Some(index) = current {
+                reachable[index] = true;
+                current = self.constraints[index].next_constraint;
+            }
+        }
+
+        let old_choice_regions = std::mem::take(&mut self.choice_regions);
+        for (index, constraint) in self.constraints.iter_enumerated_mut() {
+            if reachable[index] {
+                let start_index = self.choice_regions.len();
+                let range = constraint.start_index..constraint.end_index;
+                self.choice_regions.extend_from_slice(&old_choice_regions[range]);
+                constraint.start_index = start_index;
+                constraint.end_index = self.choice_regions.len();
+            } else {
+                constraint.start_index = 0;
+                constraint.end_index = 0;
+            }
+        }
+    }
 }
 
 impl<'tcx, R> Index<NllMemberConstraintIndex> for MemberConstraintSet<'tcx, R>